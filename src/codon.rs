@@ -0,0 +1,350 @@
+/// Coarse amino acid classification used to pick a block color for codon
+/// mode. `Stop` is its own class so stop codons can flash distinctly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AminoAcidClass {
+    Hydrophobic,
+    Polar,
+    Charged,
+    Stop,
+}
+
+/// The translation result for one codon: its single-letter amino acid code
+/// (`*` for a stop) and the class used for coloring.
+#[derive(Debug, Clone, Copy)]
+pub struct CodonInfo {
+    #[allow(dead_code)] // kept for completeness/debugging; draw() only needs `class`
+    pub amino_acid: char,
+    pub class: AminoAcidClass,
+}
+
+/// Maps a base to its index in the table's base-4 encoding (A=0, C=1, G=2,
+/// T/U=3). Returns `None` for anything else (`N`, ambiguity codes, gaps).
+pub fn base_index(ch: char) -> Option<usize> {
+    match ch {
+        'A' => Some(0),
+        'C' => Some(1),
+        'G' => Some(2),
+        'T' | 'U' => Some(3),
+        _ => None,
+    }
+}
+
+/// Computes the 0..64 table index for a triplet of bases, or `None` if any
+/// base is unknown (e.g. `N`).
+pub fn triplet_index(a: char, b: char, c: char) -> Option<usize> {
+    Some(base_index(a)? * 16 + base_index(b)? * 4 + base_index(c)?)
+}
+
+/// Looks up the amino acid and class for a triplet of bases. Returns `None`
+/// for triplets containing an unknown base, so callers can fall back to
+/// per-base coloring.
+pub fn translate(a: char, b: char, c: char) -> Option<CodonInfo> {
+    triplet_index(a, b, c).map(|idx| CODON_TABLE[idx])
+}
+
+/// The standard genetic code, indexed by `base_index(a) * 16 + base_index(b)
+/// * 4 + base_index(c)` for codon `abc`.
+const CODON_TABLE: [CodonInfo; 64] = {
+    use AminoAcidClass::*;
+    [
+        CodonInfo {
+            amino_acid: 'K',
+            class: Charged,
+        }, // AAA (0)
+        CodonInfo {
+            amino_acid: 'N',
+            class: Polar,
+        }, // AAC (1)
+        CodonInfo {
+            amino_acid: 'K',
+            class: Charged,
+        }, // AAG (2)
+        CodonInfo {
+            amino_acid: 'N',
+            class: Polar,
+        }, // AAT (3)
+        CodonInfo {
+            amino_acid: 'T',
+            class: Polar,
+        }, // ACA (4)
+        CodonInfo {
+            amino_acid: 'T',
+            class: Polar,
+        }, // ACC (5)
+        CodonInfo {
+            amino_acid: 'T',
+            class: Polar,
+        }, // ACG (6)
+        CodonInfo {
+            amino_acid: 'T',
+            class: Polar,
+        }, // ACT (7)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // AGA (8)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // AGC (9)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // AGG (10)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // AGT (11)
+        CodonInfo {
+            amino_acid: 'I',
+            class: Hydrophobic,
+        }, // ATA (12)
+        CodonInfo {
+            amino_acid: 'I',
+            class: Hydrophobic,
+        }, // ATC (13)
+        CodonInfo {
+            amino_acid: 'M',
+            class: Hydrophobic,
+        }, // ATG (14)
+        CodonInfo {
+            amino_acid: 'I',
+            class: Hydrophobic,
+        }, // ATT (15)
+        CodonInfo {
+            amino_acid: 'Q',
+            class: Polar,
+        }, // CAA (16)
+        CodonInfo {
+            amino_acid: 'H',
+            class: Charged,
+        }, // CAC (17)
+        CodonInfo {
+            amino_acid: 'Q',
+            class: Polar,
+        }, // CAG (18)
+        CodonInfo {
+            amino_acid: 'H',
+            class: Charged,
+        }, // CAT (19)
+        CodonInfo {
+            amino_acid: 'P',
+            class: Hydrophobic,
+        }, // CCA (20)
+        CodonInfo {
+            amino_acid: 'P',
+            class: Hydrophobic,
+        }, // CCC (21)
+        CodonInfo {
+            amino_acid: 'P',
+            class: Hydrophobic,
+        }, // CCG (22)
+        CodonInfo {
+            amino_acid: 'P',
+            class: Hydrophobic,
+        }, // CCT (23)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // CGA (24)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // CGC (25)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // CGG (26)
+        CodonInfo {
+            amino_acid: 'R',
+            class: Charged,
+        }, // CGT (27)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // CTA (28)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // CTC (29)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // CTG (30)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // CTT (31)
+        CodonInfo {
+            amino_acid: 'E',
+            class: Charged,
+        }, // GAA (32)
+        CodonInfo {
+            amino_acid: 'D',
+            class: Charged,
+        }, // GAC (33)
+        CodonInfo {
+            amino_acid: 'E',
+            class: Charged,
+        }, // GAG (34)
+        CodonInfo {
+            amino_acid: 'D',
+            class: Charged,
+        }, // GAT (35)
+        CodonInfo {
+            amino_acid: 'A',
+            class: Hydrophobic,
+        }, // GCA (36)
+        CodonInfo {
+            amino_acid: 'A',
+            class: Hydrophobic,
+        }, // GCC (37)
+        CodonInfo {
+            amino_acid: 'A',
+            class: Hydrophobic,
+        }, // GCG (38)
+        CodonInfo {
+            amino_acid: 'A',
+            class: Hydrophobic,
+        }, // GCT (39)
+        CodonInfo {
+            amino_acid: 'G',
+            class: Hydrophobic,
+        }, // GGA (40)
+        CodonInfo {
+            amino_acid: 'G',
+            class: Hydrophobic,
+        }, // GGC (41)
+        CodonInfo {
+            amino_acid: 'G',
+            class: Hydrophobic,
+        }, // GGG (42)
+        CodonInfo {
+            amino_acid: 'G',
+            class: Hydrophobic,
+        }, // GGT (43)
+        CodonInfo {
+            amino_acid: 'V',
+            class: Hydrophobic,
+        }, // GTA (44)
+        CodonInfo {
+            amino_acid: 'V',
+            class: Hydrophobic,
+        }, // GTC (45)
+        CodonInfo {
+            amino_acid: 'V',
+            class: Hydrophobic,
+        }, // GTG (46)
+        CodonInfo {
+            amino_acid: 'V',
+            class: Hydrophobic,
+        }, // GTT (47)
+        CodonInfo {
+            amino_acid: '*',
+            class: Stop,
+        }, // TAA (48)
+        CodonInfo {
+            amino_acid: 'Y',
+            class: Polar,
+        }, // TAC (49)
+        CodonInfo {
+            amino_acid: '*',
+            class: Stop,
+        }, // TAG (50)
+        CodonInfo {
+            amino_acid: 'Y',
+            class: Polar,
+        }, // TAT (51)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // TCA (52)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // TCC (53)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // TCG (54)
+        CodonInfo {
+            amino_acid: 'S',
+            class: Polar,
+        }, // TCT (55)
+        CodonInfo {
+            amino_acid: '*',
+            class: Stop,
+        }, // TGA (56)
+        CodonInfo {
+            amino_acid: 'C',
+            class: Polar,
+        }, // TGC (57)
+        CodonInfo {
+            amino_acid: 'W',
+            class: Hydrophobic,
+        }, // TGG (58)
+        CodonInfo {
+            amino_acid: 'C',
+            class: Polar,
+        }, // TGT (59)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // TTA (60)
+        CodonInfo {
+            amino_acid: 'F',
+            class: Hydrophobic,
+        }, // TTC (61)
+        CodonInfo {
+            amino_acid: 'L',
+            class: Hydrophobic,
+        }, // TTG (62)
+        CodonInfo {
+            amino_acid: 'F',
+            class: Hydrophobic,
+        }, // TTT (63)
+    ]
+};
+
+/// RGB color for an amino acid class. Stop codons get a bright flash color;
+/// the others use one hue per class so codons render as solid blocks.
+pub fn class_color(class: AminoAcidClass) -> (u8, u8, u8) {
+    match class {
+        AminoAcidClass::Hydrophobic => (230, 160, 0),
+        AminoAcidClass::Polar => (0, 180, 220),
+        AminoAcidClass::Charged => (220, 0, 120),
+        AminoAcidClass::Stop => (255, 255, 255),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translates_start_codon() {
+        let info = translate('A', 'T', 'G').unwrap();
+        assert_eq!(info.amino_acid, 'M');
+        assert_eq!(info.class, AminoAcidClass::Hydrophobic);
+    }
+
+    #[test]
+    fn translates_lysine_and_asparagine() {
+        assert_eq!(translate('A', 'A', 'A').unwrap().amino_acid, 'K');
+        assert_eq!(translate('A', 'A', 'C').unwrap().amino_acid, 'N');
+    }
+
+    #[test]
+    fn all_three_stop_codons_are_classified_as_stop() {
+        for (a, b, c) in [('T', 'A', 'A'), ('T', 'A', 'G'), ('T', 'G', 'A')] {
+            let info = translate(a, b, c).unwrap();
+            assert_eq!(info.amino_acid, '*');
+            assert_eq!(info.class, AminoAcidClass::Stop);
+        }
+    }
+
+    #[test]
+    fn unknown_base_yields_none() {
+        assert!(translate('A', 'N', 'G').is_none());
+    }
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+/// RGB color triple, deserialized from a `[r, g, b]` array in the TOML file.
+pub type Rgb = (u8, u8, u8);
+
+/// User-configurable knobs for the animation, loaded from
+/// `~/.config/rdna/rdna.toml`. Any field left out of the file falls back to
+/// [`Config::default`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Characters that can appear in a column, in generation order.
+    pub alphabet: Vec<char>,
+    /// Per-character RGB overrides, keyed by the character as a one-char string.
+    pub colors: std::collections::HashMap<String, Rgb>,
+    /// Column spacing in terminal cells (smaller is denser).
+    pub step: usize,
+    /// Minimum column fall speed, in rows per tick.
+    pub speed_min: u16,
+    /// Maximum column fall speed, in rows per tick (exclusive).
+    pub speed_max: u16,
+    /// Divisor applied to terminal height to derive a column's trail length.
+    pub trail_divisor: u16,
+    /// Delay between animation frames, in milliseconds.
+    pub frame_ms: u64,
+    /// Color of the bright head character at the front of each column.
+    pub head_color: Rgb,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            alphabet: vec!['A', 'T', 'C', 'G', 'U'],
+            colors: default_colors(),
+            step: 2,
+            speed_min: 1,
+            speed_max: 2,
+            trail_divisor: 3,
+            frame_ms: 60,
+            head_color: (255, 255, 255),
+        }
+    }
+}
+
+fn default_colors() -> std::collections::HashMap<String, Rgb> {
+    std::collections::HashMap::from([
+        ("A".to_string(), (0, 200, 0)),
+        ("T".to_string(), (200, 0, 0)),
+        ("C".to_string(), (0, 100, 255)),
+        ("G".to_string(), (220, 220, 0)),
+        ("U".to_string(), (153, 51, 255)),
+    ])
+}
+
+impl Config {
+    /// Path to the user config file, `~/.config/rdna/rdna.toml`.
+    fn config_path() -> Option<PathBuf> {
+        let home = std::env::var_os("HOME")?;
+        Some(PathBuf::from(home).join(".config/rdna/rdna.toml"))
+    }
+
+    /// Loads the config from disk, falling back to [`Config::default`] if the
+    /// file is missing or fails to parse.
+    pub fn load() -> Self {
+        let Some(path) = Self::config_path() else {
+            return Self::default();
+        };
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        let config: Self = toml::from_str(&contents).unwrap_or_default();
+        config.sanitized()
+    }
+
+    /// Clamps fields that would otherwise panic downstream (a zero divisor,
+    /// step, an empty alphabet, or an inverted/overflowing speed range) to
+    /// a sane value; an empty alphabet is replaced by the default one.
+    fn sanitized(mut self) -> Self {
+        self.trail_divisor = self.trail_divisor.max(1);
+        self.step = self.step.max(1);
+        if self.alphabet.is_empty() {
+            self.alphabet = Self::default().alphabet;
+        }
+        if self.speed_min >= self.speed_max {
+            self.speed_min = self.speed_min.min(u16::MAX - 1);
+            self.speed_max = self.speed_min + 1;
+        }
+        self
+    }
+
+    /// Color for a given character, falling back to the head color's
+    /// neighbor default (green) for anything not in the `colors` map.
+    pub fn color_for(&self, ch: char) -> Rgb {
+        self.colors
+            .get(ch.to_string().as_str())
+            .copied()
+            .unwrap_or((0, 200, 0))
+    }
+}
@@ -1,26 +1,40 @@
+mod codon;
+mod config;
+mod sequence;
+
+use config::Config;
 use crossterm::{
     cursor,
-    event::{self, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseEventKind},
     execute,
-    style::{Color, Print, SetForegroundColor},
-    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+    style::{Attribute, Color, Print, SetAttribute, SetForegroundColor},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use rand::Rng;
+use sequence::Sequence;
 use std::{
-    io::{self, Write, stdout},
+    io::{self, stdout, Write},
+    rc::Rc,
     time::Duration,
 };
 
-const NUCLEOTIDES: [char; 5] = ['A', 'T', 'C', 'G', 'U'];
-
-fn nucleotide_color(ch: char) -> (u8, u8, u8) {
-    match ch {
-        'A' => (0, 200, 0),    // green
-        'T' => (200, 0, 0),    // red
-        'C' => (0, 100, 255),  // blue
-        'G' => (220, 220, 0),  // yellow
-        'U' => (153, 51, 255), // purple
-        _ => (0, 200, 0),
+/// Fills `count` chars either from the shared sequence buffer (continuing
+/// from `offset`) or, when no sequence was supplied, with random bases from
+/// the configured alphabet.
+fn fill_chars(
+    sequence: Option<&Rc<Sequence>>,
+    offset: usize,
+    count: usize,
+    config: &Config,
+) -> Vec<char> {
+    match sequence {
+        Some(seq) if !seq.is_empty() => seq.slice_wrapping(offset, count),
+        _ => {
+            let mut rng = rand::thread_rng();
+            (0..count)
+                .map(|_| config.alphabet[rng.gen_range(0..config.alphabet.len())])
+                .collect()
+        }
     }
 }
 
@@ -30,24 +44,59 @@ struct Column {
     speed: u16,
     trail_len: i16,
     chars: Vec<char>,
+    sequence: Option<Rc<Sequence>>,
+    seq_offset: usize,
+    /// Reading frame (0, 1 or 2) for codon coloring, or `None` in per-base mode.
+    codon_frame: Option<u8>,
 }
 
 impl Column {
-    fn new(x: u16, height: u16) -> Self {
+    fn new(
+        x: u16,
+        height: u16,
+        config: &Config,
+        sequence: Option<&Rc<Sequence>>,
+        codon_frame: Option<u8>,
+    ) -> Self {
         let mut rng = rand::thread_rng();
-        let trail_len = (height / 3).max(4) as i16;
+        let trail_len = (height / config.trail_divisor).max(4) as i16;
+        let seq_offset = sequence
+            .filter(|seq| !seq.is_empty())
+            .map(|seq| rng.gen_range(0..seq.len()))
+            .unwrap_or(0);
         Self {
             x,
             y: rng.gen_range(-trail_len..0),
-            speed: rng.gen_range(1..2),
+            speed: rng.gen_range(config.speed_min..config.speed_max),
             trail_len,
-            chars: (0..height)
-                .map(|_| NUCLEOTIDES[rng.gen_range(0..NUCLEOTIDES.len())])
-                .collect(),
+            chars: fill_chars(sequence, seq_offset, height as usize, config),
+            sequence: sequence.cloned(),
+            seq_offset,
+            codon_frame,
+        }
+    }
+
+    /// Returns the translation of the reading-frame-aligned codon the given
+    /// row belongs to, or `None` in per-base mode, when the triplet falls
+    /// off either end of the column's current chars buffer, or when the
+    /// triplet holds an unknown base.
+    fn codon_at(&self, row: usize) -> Option<codon::CodonInfo> {
+        let frame = self.codon_frame? as usize;
+        if row < frame {
+            return None;
         }
+        let triplet_start = frame + (row - frame) / 3 * 3;
+        let bases = self.chars.get(triplet_start..triplet_start + 3)?;
+        codon::translate(bases[0], bases[1], bases[2])
     }
 
-    fn draw(&self, stdout: &mut impl Write, height: u16) -> io::Result<()> {
+    fn draw(
+        &self,
+        stdout: &mut impl Write,
+        height: u16,
+        config: &Config,
+        palette: Palette,
+    ) -> io::Result<()> {
         for i in 0..=self.trail_len {
             let row = self.y - i;
             if row < 0 || row >= height as i16 {
@@ -55,15 +104,29 @@ impl Column {
             }
             let ch = self.chars[row as usize];
             let color = match i {
-                // Head - bright white
-                0 => Color::Rgb {
-                    r: 255,
-                    g: 255,
-                    b: 255,
-                },
+                // Head - configurable bright color
+                0 => {
+                    let (r, g, b) = config.head_color;
+                    Color::Rgb { r, g, b }
+                }
                 _ => {
-                    // Body/tail - nucleotide color, dimming toward the tail
-                    let (r, g, b) = nucleotide_color(ch);
+                    // Body/tail - palette-selected color, dimming toward the tail
+                    let (r, g, b) = match palette {
+                        Palette::ClassicGreen => (0, 200, 0),
+                        Palette::Monochrome => (200, 200, 200),
+                        Palette::PerBase => match self.codon_at(row as usize) {
+                            Some(info) if info.class == codon::AminoAcidClass::Stop => {
+                                // Flash the stop codon as the column falls past it
+                                if self.y % 2 == 0 {
+                                    (255, 0, 0)
+                                } else {
+                                    (255, 255, 255)
+                                }
+                            }
+                            Some(info) => codon::class_color(info.class),
+                            None => config.color_for(ch),
+                        },
+                    };
                     let fade = 1.0 - (i as f32 / self.trail_len as f32);
                     Color::Rgb {
                         r: (r as f32 * fade) as u8,
@@ -89,62 +152,311 @@ impl Column {
         Ok(())
     }
 
-    fn update(&mut self, height: u16) {
+    /// Resets this column's head to `row` and regenerates its chars, as if
+    /// it had just wrapped around. Used to seed a column from a mouse click.
+    /// `burst` sets the fall speed to twice the configured maximum for a fast
+    /// click-drag head, until the column next wraps around.
+    fn strike(&mut self, row: u16, config: &Config, burst: bool) {
+        let mut rng = rand::thread_rng();
+        self.y = row as i16;
+        if let Some(seq) = self.sequence.as_ref().filter(|seq| !seq.is_empty()) {
+            self.seq_offset = rng.gen_range(0..seq.len());
+        }
+        self.chars = fill_chars(
+            self.sequence.as_ref(),
+            self.seq_offset,
+            self.chars.len(),
+            config,
+        );
+        if burst {
+            self.speed = config.speed_max * 2;
+        }
+    }
+
+    fn resize(&mut self, height: u16, config: &Config) {
+        self.trail_len = (height / config.trail_divisor).max(4) as i16;
+        self.chars = fill_chars(
+            self.sequence.as_ref(),
+            self.seq_offset,
+            height as usize,
+            config,
+        );
+        self.y = self.y.min(height as i16 - 1);
+    }
+
+    /// Regenerates this column's chars in place, as if a fresh sequence
+    /// slice (or random draw) had replaced what's currently falling.
+    fn reseed(&mut self, config: &Config) {
+        let mut rng = rand::thread_rng();
+        if let Some(seq) = self.sequence.as_ref().filter(|seq| !seq.is_empty()) {
+            self.seq_offset = rng.gen_range(0..seq.len());
+        }
+        self.chars = fill_chars(
+            self.sequence.as_ref(),
+            self.seq_offset,
+            self.chars.len(),
+            config,
+        );
+    }
+
+    fn update(&mut self, height: u16, config: &Config, speed_multiplier: f32) {
         let mut rng = rand::thread_rng();
-        self.y += self.speed as i16;
+        self.y += ((self.speed as f32 * speed_multiplier).round() as i16).max(1);
         if self.y - self.trail_len > height as i16 {
             self.y = rng.gen_range(-self.trail_len..0);
-            // Regenerate characters for variety
-            for ch in &mut self.chars {
-                *ch = NUCLEOTIDES[rng.gen_range(0..4)];
+            // A burst head from a click-drag only lasts one pass
+            if self.speed >= config.speed_max {
+                self.speed = rng.gen_range(config.speed_min..config.speed_max);
+            }
+            // Advance through the sequence (or regenerate randomly) for variety
+            if let Some(seq) = self.sequence.as_ref().filter(|seq| !seq.is_empty()) {
+                self.seq_offset = (self.seq_offset + self.chars.len()) % seq.len();
             }
+            self.chars = fill_chars(
+                self.sequence.as_ref(),
+                self.seq_offset,
+                height as usize,
+                config,
+            );
         }
     }
 }
 
 fn setup_terminal(stdout: &mut impl Write) -> io::Result<()> {
     terminal::enable_raw_mode()?;
-    execute!(stdout, EnterAlternateScreen, cursor::Hide)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        cursor::Hide,
+        EnableMouseCapture
+    )?;
     Ok(())
 }
 
 fn cleanup_terminal(stdout: &mut impl Write) -> io::Result<()> {
-    execute!(stdout, LeaveAlternateScreen, cursor::Show)?;
+    execute!(
+        stdout,
+        DisableMouseCapture,
+        LeaveAlternateScreen,
+        cursor::Show
+    )?;
     terminal::disable_raw_mode()?;
     Ok(())
 }
 
-fn run(stdout: &mut impl Write) -> io::Result<()> {
-    let (width, height) = terminal::size()?;
+/// Finds the column whose `x` is closest to a clicked/dragged terminal column.
+fn nearest_column(columns: &mut [Column], x: u16) -> Option<&mut Column> {
+    columns.iter_mut().min_by_key(|col| col.x.abs_diff(x))
+}
+
+/// Built-in color palettes, cycled at runtime with a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Palette {
+    /// Colors from `Config`/codon mode, as set up at startup.
+    PerBase,
+    /// Classic green Matrix rain, regardless of base or codon.
+    ClassicGreen,
+    /// Grayscale rain.
+    Monochrome,
+}
+
+impl Palette {
+    fn next(self) -> Self {
+        match self {
+            Palette::PerBase => Palette::ClassicGreen,
+            Palette::ClassicGreen => Palette::Monochrome,
+            Palette::Monochrome => Palette::PerBase,
+        }
+    }
+}
+
+/// Mutable playback state driven by runtime keyboard controls: pause,
+/// speed scaling, and the active color palette.
+struct RunState {
+    paused: bool,
+    speed_multiplier: f32,
+    palette: Palette,
+}
+
+impl RunState {
+    fn new() -> Self {
+        Self {
+            paused: false,
+            speed_multiplier: 1.0,
+            palette: Palette::PerBase,
+        }
+    }
+
+    fn poll_duration(&self, config: &Config) -> Duration {
+        let ms = (config.frame_ms as f32 / self.speed_multiplier).max(5.0);
+        Duration::from_millis(ms as u64)
+    }
+}
+
+fn run(
+    stdout: &mut impl Write,
+    config: &Config,
+    sequence: Option<Rc<Sequence>>,
+    codon_frame: Option<u8>,
+) -> io::Result<()> {
+    let (mut width, mut height) = terminal::size()?;
     let mut columns: Vec<Column> = (0..width)
-        .step_by(2)
-        .map(|x| Column::new(x, height))
+        .step_by(config.step)
+        .map(|x| Column::new(x, height, config, sequence.as_ref(), codon_frame))
         .collect();
+    let mut cursor_cell: Option<(u16, u16)> = None;
+    let mut prev_cursor_cell: Option<(u16, u16)> = None;
+    let mut run_state = RunState::new();
 
     loop {
-        if event::poll(Duration::from_millis(60))? {
-            if let Event::Key(key) = event::read()? {
-                if key.code == KeyCode::Char('q') || key.code == KeyCode::Esc {
-                    break;
+        if event::poll(run_state.poll_duration(config))? {
+            match event::read()? {
+                Event::Key(key) => match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => break,
+                    KeyCode::Char(' ') => run_state.paused = !run_state.paused,
+                    KeyCode::Char('+') => {
+                        run_state.speed_multiplier = (run_state.speed_multiplier * 1.25).min(8.0)
+                    }
+                    KeyCode::Char('-') => {
+                        run_state.speed_multiplier = (run_state.speed_multiplier / 1.25).max(0.1)
+                    }
+                    KeyCode::Char('r') => {
+                        for col in &mut columns {
+                            col.reseed(config);
+                        }
+                    }
+                    KeyCode::Char('c') => run_state.palette = run_state.palette.next(),
+                    _ => {}
+                },
+                Event::Resize(w, h) => {
+                    width = w;
+                    height = h;
+                    columns.retain(|col| col.x < width);
+                    let existing: std::collections::HashSet<u16> =
+                        columns.iter().map(|col| col.x).collect();
+                    for x in (0..width).step_by(config.step) {
+                        if !existing.contains(&x) {
+                            columns.push(Column::new(
+                                x,
+                                height,
+                                config,
+                                sequence.as_ref(),
+                                codon_frame,
+                            ));
+                        }
+                    }
+                    for col in &mut columns {
+                        col.resize(height, config);
+                    }
+                    execute!(stdout, Clear(ClearType::All))?;
+                    prev_cursor_cell = None;
+                }
+                Event::Mouse(mouse) => {
+                    cursor_cell = Some((mouse.column, mouse.row));
+                    match mouse.kind {
+                        MouseEventKind::Down(_) => {
+                            if let Some(col) = nearest_column(&mut columns, mouse.column) {
+                                col.strike(mouse.row, config, false);
+                            }
+                        }
+                        MouseEventKind::Drag(_) => {
+                            if let Some(col) = nearest_column(&mut columns, mouse.column) {
+                                col.strike(mouse.row, config, true);
+                            }
+                        }
+                        _ => {}
+                    }
                 }
+                _ => {}
             }
         }
 
+        // Erase the previous cursor highlight before the rain redraws this
+        // frame, so a moved cursor never leaves a stuck inverted cell behind.
+        if let Some((px, py)) = prev_cursor_cell.filter(|cell| Some(*cell) != cursor_cell) {
+            execute!(stdout, cursor::MoveTo(px, py), Print(' '))?;
+        }
+
         for col in &mut columns {
-            col.draw(stdout, height)?;
-            col.update(height);
+            col.draw(stdout, height, config, run_state.palette)?;
+            if !run_state.paused {
+                col.update(height, config, run_state.speed_multiplier);
+            }
         }
 
+        cursor_cell = cursor_cell.filter(|(cx, cy)| *cx < width && *cy < height);
+        if let Some((cx, cy)) = cursor_cell {
+            execute!(
+                stdout,
+                cursor::MoveTo(cx, cy),
+                SetAttribute(Attribute::Reverse),
+                Print(' '),
+                SetAttribute(Attribute::Reset)
+            )?;
+        }
+        prev_cursor_cell = cursor_cell;
+
         stdout.flush()?;
     }
 
     Ok(())
 }
 
+/// Command-line options recognized by rdna.
+struct Args {
+    sequence: Option<Sequence>,
+    /// Reading frame (0, 1 or 2) when `--codons` was passed.
+    codon_frame: Option<u8>,
+}
+
+/// Parses the command line.
+///
+/// `--fasta <path>` reads a FASTA file; `--stdin` reads FASTA (or bare
+/// bases) from standard input. With neither flag, rdna falls back to the
+/// existing random-base generation. `--codons [frame]` switches to
+/// amino-acid-class coloring, aligned to reading frame 0 (default), 1 or 2.
+fn parse_args() -> io::Result<Args> {
+    let mut sequence = None;
+    let mut codon_frame = None;
+
+    let mut args = std::env::args().skip(1).peekable();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--fasta" => {
+                let path = args.next().unwrap_or_else(|| {
+                    eprintln!("--fasta requires a file path");
+                    std::process::exit(1);
+                });
+                sequence = Some(Sequence::from_fasta_file(&path)?);
+            }
+            "--stdin" => sequence = Some(Sequence::from_stdin()?),
+            "--codons" => {
+                let frame = args
+                    .peek()
+                    .and_then(|s| s.parse::<u8>().ok())
+                    .filter(|f| *f < 3);
+                if frame.is_some() {
+                    args.next();
+                }
+                codon_frame = Some(frame.unwrap_or(0));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(Args {
+        sequence,
+        codon_frame,
+    })
+}
+
 fn main() -> io::Result<()> {
+    let config = Config::load();
+    let args = parse_args()?;
+    let sequence = args.sequence.map(Rc::new);
     let mut stdout = stdout();
     setup_terminal(&mut stdout)?;
-    let result = run(&mut stdout);
+    let result = run(&mut stdout, &config, sequence, args.codon_frame);
     cleanup_terminal(&mut stdout)?;
     result
 }
@@ -0,0 +1,97 @@
+use std::io::{self, Read};
+
+/// A flat buffer of nucleotide bases read from a FASTA file or stdin,
+/// shared read-only across all columns so the rain streams real sequence
+/// data instead of random bases.
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    bases: Vec<char>,
+}
+
+impl Sequence {
+    /// Parses FASTA-formatted text into a flat base buffer. Header lines
+    /// (starting with `>`) are dropped, whitespace and line wrapping in the
+    /// body are ignored, and lowercase bases are upper-cased. `N` and other
+    /// ambiguity codes are kept as-is.
+    pub fn parse_fasta(text: &str) -> Self {
+        let bases = text
+            .lines()
+            .filter(|line| !line.starts_with('>'))
+            .flat_map(|line| line.chars())
+            .filter(|ch| !ch.is_whitespace())
+            .map(|ch| ch.to_ascii_uppercase())
+            .collect();
+        Self { bases }
+    }
+
+    /// Reads a FASTA file from disk.
+    pub fn from_fasta_file(path: &str) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(Self::parse_fasta(&text))
+    }
+
+    /// Reads raw sequence data (FASTA or bare bases) from stdin.
+    pub fn from_stdin() -> io::Result<Self> {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(Self::parse_fasta(&text))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bases.len()
+    }
+
+    /// Returns `count` bases starting at `offset`, wrapping around the end
+    /// of the buffer.
+    pub fn slice_wrapping(&self, offset: usize, count: usize) -> Vec<char> {
+        (0..count)
+            .map(|i| self.bases[(offset + i) % self.bases.len()])
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_header_and_upcases_mixed_case() {
+        let seq = Sequence::parse_fasta(">seq1 description\nAtcGn");
+        assert_eq!(
+            seq.slice_wrapping(0, seq.len()),
+            vec!['A', 'T', 'C', 'G', 'N']
+        );
+    }
+
+    #[test]
+    fn drops_whitespace_and_joins_wrapped_lines() {
+        let seq = Sequence::parse_fasta(">seq1\nACGT\nACGT\n");
+        assert_eq!(seq.len(), 8);
+        assert_eq!(
+            seq.slice_wrapping(0, 8),
+            vec!['A', 'C', 'G', 'T', 'A', 'C', 'G', 'T']
+        );
+    }
+
+    #[test]
+    fn concatenates_multiple_records() {
+        let seq = Sequence::parse_fasta(">seq1\nAC\n>seq2\nGT\n");
+        assert_eq!(seq.slice_wrapping(0, 4), vec!['A', 'C', 'G', 'T']);
+    }
+
+    #[test]
+    fn slice_wrapping_wraps_around_the_end() {
+        let seq = Sequence::parse_fasta("ACGT");
+        assert_eq!(seq.slice_wrapping(2, 4), vec!['G', 'T', 'A', 'C']);
+    }
+
+    #[test]
+    fn empty_input_yields_empty_sequence() {
+        let seq = Sequence::parse_fasta(">seq1\n\n");
+        assert!(seq.is_empty());
+    }
+}